@@ -0,0 +1,137 @@
+//! Integer combinatorial and number-theory functions.
+
+use crate::CalculatorError;
+
+/// Calculates `n!`, the product of all positive integers up to `n`.
+///
+/// # Arguments
+///
+/// * `n` - The number to take the factorial of
+///
+/// # Errors
+///
+/// Returns `CalculatorError::Overflow` if the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use calculator::combinatorics::factorial;
+///
+/// assert_eq!(factorial(5).unwrap(), 120);
+/// ```
+pub fn factorial(n: u64) -> Result<u64, CalculatorError> {
+    (1..=n).try_fold(1u64, |acc, i| acc.checked_mul(i).ok_or(CalculatorError::Overflow))
+}
+
+/// Calculates the binomial coefficient `C(n, k)`, the number of ways to
+/// choose `k` items from `n` without regard to order.
+///
+/// # Arguments
+///
+/// * `n` - The size of the set to choose from
+/// * `k` - The number of items to choose
+///
+/// # Errors
+///
+/// Returns `CalculatorError::Overflow` if an intermediate product overflows
+/// a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use calculator::combinatorics::binomial;
+///
+/// assert_eq!(binomial(5, 2).unwrap(), 10);
+/// ```
+pub fn binomial(n: u64, k: u64) -> Result<u64, CalculatorError> {
+    if k > n {
+        return Ok(0);
+    }
+    let k = k.min(n - k);
+    let mut result = 1u64;
+    for i in 1..=k {
+        result = result
+            .checked_mul(n - k + i)
+            .ok_or(CalculatorError::Overflow)?
+            / i;
+    }
+    Ok(result)
+}
+
+/// Calculates the greatest common divisor of `a` and `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// use calculator::combinatorics::gcd;
+///
+/// assert_eq!(gcd(12, 18), 6);
+/// ```
+#[must_use]
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Calculates the least common multiple of `a` and `b`.
+///
+/// # Examples
+///
+/// ```rust
+/// use calculator::combinatorics::lcm;
+///
+/// assert_eq!(lcm(4, 6), 12);
+/// ```
+#[must_use]
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0).unwrap(), 1);
+        assert_eq!(factorial(5).unwrap(), 120);
+    }
+
+    #[test]
+    fn test_factorial_overflow() {
+        assert!(factorial(21).is_err());
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(5, 2).unwrap(), 10);
+        assert_eq!(binomial(10, 0).unwrap(), 1);
+        assert_eq!(binomial(10, 10).unwrap(), 1);
+        assert_eq!(binomial(3, 5).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_binomial_large_no_overflow() {
+        // Intermediate products stay within u64 even though n! itself would not.
+        assert_eq!(binomial(62, 31).unwrap(), 465_428_353_255_261_088);
+    }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 5), 0);
+    }
+}
@@ -0,0 +1,209 @@
+//! Infix expression parsing and evaluation, using the shunting-yard algorithm.
+
+use crate::{Calculator, CalculatorError, Operation};
+
+/// A single lexical token in an infix expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    Number(f64),
+    Op(Operation),
+    LParen,
+    RParen,
+}
+
+/// Splits an expression into numbers, operators, and parentheses.
+fn tokenize(expr: &str) -> Result<Vec<Token>, CalculatorError> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            c if c.is_whitespace() => i += 1,
+            '+' => {
+                tokens.push(Token::Op(Operation::Add));
+                i += 1;
+            }
+            '-' => {
+                tokens.push(Token::Op(Operation::Subtract));
+                i += 1;
+            }
+            '*' => {
+                tokens.push(Token::Op(Operation::Multiply));
+                i += 1;
+            }
+            '/' => {
+                tokens.push(Token::Op(Operation::Divide));
+                i += 1;
+            }
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            c if c.is_ascii_digit() || c == '.' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| CalculatorError::ParseError(format!("invalid number '{text}'")))?;
+                tokens.push(Token::Number(value));
+            }
+            other => {
+                return Err(CalculatorError::ParseError(format!(
+                    "unexpected character '{other}'"
+                )))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Returns the precedence of a binary operator; `*`/`/` bind tighter than `+`/`-`.
+fn precedence(op: Operation) -> u8 {
+    match op {
+        Operation::Add | Operation::Subtract => 1,
+        Operation::Multiply | Operation::Divide => 2,
+        Operation::Power | Operation::Atan2 | Operation::Log => {
+            unreachable!("the tokenizer never produces a scientific-only operator")
+        }
+    }
+}
+
+/// Converts tokens from infix to reverse Polish notation via shunting-yard.
+fn to_rpn(tokens: Vec<Token>) -> Result<Vec<Token>, CalculatorError> {
+    let mut output = Vec::new();
+    let mut operators: Vec<Token> = Vec::new();
+    for token in tokens {
+        match token {
+            Token::Number(_) => output.push(token),
+            Token::Op(op) => {
+                while let Some(Token::Op(top)) = operators.last() {
+                    if precedence(*top) >= precedence(op) {
+                        output.push(operators.pop().expect("just peeked"));
+                    } else {
+                        break;
+                    }
+                }
+                operators.push(token);
+            }
+            Token::LParen => operators.push(token),
+            Token::RParen => loop {
+                match operators.pop() {
+                    Some(Token::LParen) => break,
+                    Some(op) => output.push(op),
+                    None => {
+                        return Err(CalculatorError::ParseError(
+                            "unbalanced parentheses".to_string(),
+                        ))
+                    }
+                }
+            },
+        }
+    }
+    while let Some(token) = operators.pop() {
+        if token == Token::LParen {
+            return Err(CalculatorError::ParseError(
+                "unbalanced parentheses".to_string(),
+            ));
+        }
+        output.push(token);
+    }
+    Ok(output)
+}
+
+/// Evaluates an RPN token stream, recording each binary step into `calc`'s history.
+fn eval_rpn(calc: &mut Calculator<f64>, rpn: Vec<Token>) -> Result<f64, CalculatorError> {
+    let mut stack: Vec<f64> = Vec::new();
+    for token in rpn {
+        match token {
+            Token::Number(n) => stack.push(n),
+            Token::Op(op) => {
+                let b = stack
+                    .pop()
+                    .ok_or_else(|| CalculatorError::ParseError("not enough operands".to_string()))?;
+                let a = stack
+                    .pop()
+                    .ok_or_else(|| CalculatorError::ParseError("not enough operands".to_string()))?;
+                let result = match op {
+                    Operation::Add => calc.add(a, b),
+                    Operation::Subtract => calc.subtract(a, b),
+                    Operation::Multiply => calc.multiply(a, b),
+                    Operation::Divide => calc.divide(a, b)?,
+                    Operation::Power | Operation::Atan2 | Operation::Log => {
+                        unreachable!("the tokenizer never produces a scientific-only operator")
+                    }
+                };
+                stack.push(result);
+            }
+            Token::LParen | Token::RParen => {
+                unreachable!("to_rpn consumes all parentheses")
+            }
+        }
+    }
+    match stack.len() {
+        1 => Ok(stack[0]),
+        _ => Err(CalculatorError::ParseError(
+            "malformed expression".to_string(),
+        )),
+    }
+}
+
+/// Parses and evaluates an infix expression against `calc`, recording each
+/// binary operation into its history.
+pub(crate) fn evaluate(calc: &mut Calculator<f64>, expr: &str) -> Result<f64, CalculatorError> {
+    let tokens = tokenize(expr)?;
+    let rpn = to_rpn(tokens)?;
+    eval_rpn(calc, rpn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_precedence() {
+        let mut calc = Calculator::new();
+        assert_eq!(evaluate(&mut calc, "3 + 4 * 2").unwrap(), 11.0);
+    }
+
+    #[test]
+    fn test_evaluate_parentheses() {
+        let mut calc = Calculator::new();
+        let result = evaluate(&mut calc, "3 + 4 * 2 / (1 - 5)").unwrap();
+        assert_eq!(result, 1.0);
+    }
+
+    #[test]
+    fn test_evaluate_records_history() {
+        let mut calc = Calculator::new();
+        evaluate(&mut calc, "1 + 2 + 3").unwrap();
+        assert_eq!(calc.history().len(), 2);
+    }
+
+    #[test]
+    fn test_evaluate_division_by_zero() {
+        let mut calc = Calculator::new();
+        assert_eq!(
+            evaluate(&mut calc, "1 / 0"),
+            Err(CalculatorError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_unbalanced_parens() {
+        let mut calc = Calculator::new();
+        assert!(evaluate(&mut calc, "(1 + 2").is_err());
+    }
+
+    #[test]
+    fn test_evaluate_unexpected_token() {
+        let mut calc = Calculator::new();
+        assert!(evaluate(&mut calc, "1 + a").is_err());
+    }
+}
@@ -15,12 +15,25 @@
 //! assert_eq!(result, 8.0);
 //! ```
 
+pub mod combinatorics;
+mod expression;
+mod number;
 mod operations;
 
-pub use operations::{Operation, OperationResult};
+pub use number::Number;
+pub use operations::{Operand, Operation, OperationResult};
 
 /// A calculator that performs basic arithmetic operations.
 ///
+/// `Calculator` is generic over any type implementing [`Number`] and
+/// defaults to `f64` so existing callers that write `Calculator::new()`
+/// keep working unchanged, as long as a later call (e.g. passing `f64`
+/// operands to [`add`](Calculator::add)) fixes `T`; `Calculator::new()`
+/// alone, with nothing else constraining `T`, needs an annotation such as
+/// `let calc: Calculator = Calculator::new();`. Use `Calculator::<i64>::new()`
+/// (or any other [`Number`] implementor) to get integer arithmetic with no
+/// floating-point rounding.
+///
 /// The `Calculator` struct maintains a history of all operations performed,
 /// allowing users to review previous calculations.
 ///
@@ -32,14 +45,25 @@ pub use operations::{Operation, OperationResult};
 /// let mut calc = Calculator::new();
 /// let sum = calc.add(10.0, 5.0);
 /// assert_eq!(sum, 15.0);
+///
+/// let mut int_calc = Calculator::<i64>::new();
+/// assert_eq!(int_calc.add(10, 5), 15);
 /// ```
-#[derive(Debug, Default)]
-pub struct Calculator {
+#[derive(Debug)]
+pub struct Calculator<T: Number = f64> {
     /// History of operations performed
-    history: Vec<OperationResult>,
+    history: Vec<OperationResult<T>>,
+}
+
+impl<T: Number> Default for Calculator<T> {
+    fn default() -> Self {
+        Self {
+            history: Vec::new(),
+        }
+    }
 }
 
-impl Calculator {
+impl<T: Number> Calculator<T> {
     /// Creates a new Calculator instance with an empty history.
     ///
     /// # Returns
@@ -51,14 +75,12 @@ impl Calculator {
     /// ```rust
     /// use calculator::Calculator;
     ///
-    /// let calc = Calculator::new();
+    /// let calc: Calculator = Calculator::new();
     /// assert!(calc.history().is_empty());
     /// ```
     #[must_use]
     pub fn new() -> Self {
-        Self {
-            history: Vec::new(),
-        }
+        Self::default()
     }
 
     /// Adds two numbers together.
@@ -80,7 +102,7 @@ impl Calculator {
     /// let mut calc = Calculator::new();
     /// assert_eq!(calc.add(2.0, 3.0), 5.0);
     /// ```
-    pub fn add(&mut self, a: f64, b: f64) -> f64 {
+    pub fn add(&mut self, a: T, b: T) -> T {
         let result = a + b;
         self.record(a, b, Operation::Add, result);
         result
@@ -105,7 +127,7 @@ impl Calculator {
     /// let mut calc = Calculator::new();
     /// assert_eq!(calc.subtract(10.0, 4.0), 6.0);
     /// ```
-    pub fn subtract(&mut self, a: f64, b: f64) -> f64 {
+    pub fn subtract(&mut self, a: T, b: T) -> T {
         let result = a - b;
         self.record(a, b, Operation::Subtract, result);
         result
@@ -130,7 +152,7 @@ impl Calculator {
     /// let mut calc = Calculator::new();
     /// assert_eq!(calc.multiply(3.0, 4.0), 12.0);
     /// ```
-    pub fn multiply(&mut self, a: f64, b: f64) -> f64 {
+    pub fn multiply(&mut self, a: T, b: T) -> T {
         let result = a * b;
         self.record(a, b, Operation::Multiply, result);
         result
@@ -160,8 +182,8 @@ impl Calculator {
     /// assert_eq!(calc.divide(10.0, 2.0).unwrap(), 5.0);
     /// assert!(calc.divide(10.0, 0.0).is_err());
     /// ```
-    pub fn divide(&mut self, a: f64, b: f64) -> Result<f64, CalculatorError> {
-        if b == 0.0 {
+    pub fn divide(&mut self, a: T, b: T) -> Result<T, CalculatorError> {
+        if b.is_zero() {
             return Err(CalculatorError::DivisionByZero);
         }
         let result = a / b;
@@ -184,22 +206,91 @@ impl Calculator {
     /// # Panics
     ///
     /// Panics if dividing by zero. Use [`Calculator::divide`] for safe division.
-    pub fn calculate(&mut self, a: f64, b: f64, op: Operation) -> f64 {
+    /// Also panics if `op` is [`Operation::Power`], [`Operation::Atan2`], or
+    /// [`Operation::Log`] — those are only produced by [`ScientificCalculator`]
+    /// and have no generic `Calculator<T>` equivalent.
+    ///
+    /// [`ScientificCalculator`]: crate::ScientificCalculator
+    pub fn calculate(&mut self, a: T, b: T, op: Operation) -> T {
         match op {
             Operation::Add => self.add(a, b),
             Operation::Subtract => self.subtract(a, b),
             Operation::Multiply => self.multiply(a, b),
             Operation::Divide => self.divide(a, b).expect("Division by zero"),
+            Operation::Power | Operation::Atan2 | Operation::Log => {
+                unreachable!("{op} is only produced by ScientificCalculator")
+            }
         }
     }
 
+    /// Adds two numbers, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first operand
+    /// * `b` - The second operand
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalculatorError::Overflow` if the result overflows (for
+    /// integer types) or is non-finite (for floating-point types).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use calculator::Calculator;
+    ///
+    /// let mut calc = Calculator::<i32>::new();
+    /// assert_eq!(calc.add_checked(2, 3).unwrap(), 5);
+    /// assert!(calc.add_checked(i32::MAX, 1).is_err());
+    /// ```
+    pub fn add_checked(&mut self, a: T, b: T) -> Result<T, CalculatorError> {
+        let result = a.checked_add(b).ok_or(CalculatorError::Overflow)?;
+        self.record(a, b, Operation::Add, result);
+        Ok(result)
+    }
+
+    /// Subtracts the second number from the first, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The minuend
+    /// * `b` - The subtrahend
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalculatorError::Overflow` if the result overflows (for
+    /// integer types) or is non-finite (for floating-point types).
+    pub fn subtract_checked(&mut self, a: T, b: T) -> Result<T, CalculatorError> {
+        let result = a.checked_sub(b).ok_or(CalculatorError::Overflow)?;
+        self.record(a, b, Operation::Subtract, result);
+        Ok(result)
+    }
+
+    /// Multiplies two numbers, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first factor
+    /// * `b` - The second factor
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalculatorError::Overflow` if the result overflows (for
+    /// integer types) or is non-finite (for floating-point types).
+    pub fn multiply_checked(&mut self, a: T, b: T) -> Result<T, CalculatorError> {
+        let result = a.checked_mul(b).ok_or(CalculatorError::Overflow)?;
+        self.record(a, b, Operation::Multiply, result);
+        Ok(result)
+    }
+
     /// Returns a reference to the operation history.
     ///
     /// # Returns
     ///
     /// A slice containing all operations performed by this calculator.
     #[must_use]
-    pub fn history(&self) -> &[OperationResult] {
+    pub fn history(&self) -> &[OperationResult<T>] {
         &self.history
     }
 
@@ -221,7 +312,7 @@ impl Calculator {
     }
 
     /// Records an operation in the history.
-    fn record(&mut self, a: f64, b: f64, op: Operation, result: f64) {
+    fn record(&mut self, a: T, b: T, op: Operation, result: T) {
         self.history.push(OperationResult {
             operand_a: a,
             operand_b: b,
@@ -231,6 +322,40 @@ impl Calculator {
     }
 }
 
+impl Calculator<f64> {
+    /// Parses and evaluates an infix arithmetic expression, such as
+    /// `"3 + 4 * 2 / (1 - 5)"`.
+    ///
+    /// Supports the four basic operators and parentheses, with the usual
+    /// precedence (`*`/`/` bind tighter than `+`/`-`). Each binary step of
+    /// the evaluation is recorded into [`history`](Calculator::history), the
+    /// same as if [`add`](Calculator::add), [`subtract`](Calculator::subtract),
+    /// [`multiply`](Calculator::multiply), and [`divide`](Calculator::divide)
+    /// had been called directly.
+    ///
+    /// # Arguments
+    ///
+    /// * `expr` - The infix expression to evaluate
+    ///
+    /// # Errors
+    ///
+    /// Returns `CalculatorError::ParseError` if `expr` contains unexpected
+    /// tokens or unbalanced parentheses, or `CalculatorError::DivisionByZero`
+    /// if it divides by zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use calculator::Calculator;
+    ///
+    /// let mut calc = Calculator::new();
+    /// assert_eq!(calc.evaluate("3 + 4 * 2").unwrap(), 11.0);
+    /// ```
+    pub fn evaluate(&mut self, expr: &str) -> Result<f64, CalculatorError> {
+        expression::evaluate(self, expr)
+    }
+}
+
 /// Errors that can occur during calculator operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CalculatorError {
@@ -238,6 +363,8 @@ pub enum CalculatorError {
     DivisionByZero,
     /// The result overflowed.
     Overflow,
+    /// An expression passed to [`Calculator::evaluate`] could not be parsed.
+    ParseError(String),
 }
 
 impl std::fmt::Display for CalculatorError {
@@ -245,6 +372,7 @@ impl std::fmt::Display for CalculatorError {
         match self {
             Self::DivisionByZero => write!(f, "Cannot divide by zero"),
             Self::Overflow => write!(f, "Calculation resulted in overflow"),
+            Self::ParseError(message) => write!(f, "Failed to parse expression: {message}"),
         }
     }
 }
@@ -295,12 +423,7 @@ impl ScientificCalculator {
     /// The sine of the angle.
     #[must_use]
     pub fn sin(&self, angle: f64) -> f64 {
-        let radians = if self.use_degrees {
-            angle.to_radians()
-        } else {
-            angle
-        };
-        radians.sin()
+        self.to_radians(angle).sin()
     }
 
     /// Calculates the cosine of an angle.
@@ -314,12 +437,109 @@ impl ScientificCalculator {
     /// The cosine of the angle.
     #[must_use]
     pub fn cos(&self, angle: f64) -> f64 {
-        let radians = if self.use_degrees {
-            angle.to_radians()
-        } else {
-            angle
-        };
-        radians.cos()
+        self.to_radians(angle).cos()
+    }
+
+    /// Calculates the tangent of an angle.
+    ///
+    /// # Arguments
+    ///
+    /// * `angle` - The angle (in degrees or radians based on settings)
+    ///
+    /// # Returns
+    ///
+    /// The tangent of the angle.
+    #[must_use]
+    pub fn tan(&self, angle: f64) -> f64 {
+        self.to_radians(angle).tan()
+    }
+
+    /// Calculates the arcsine of a number, in the configured angle unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number, expected to be in `[-1, 1]`
+    ///
+    /// # Returns
+    ///
+    /// The arcsine of x, or NaN if x is outside `[-1, 1]`.
+    #[must_use]
+    pub fn asin(&self, x: f64) -> f64 {
+        self.angle_from_radians(x.asin())
+    }
+
+    /// Calculates the arccosine of a number, in the configured angle unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number, expected to be in `[-1, 1]`
+    ///
+    /// # Returns
+    ///
+    /// The arccosine of x, or NaN if x is outside `[-1, 1]`.
+    #[must_use]
+    pub fn acos(&self, x: f64) -> f64 {
+        self.angle_from_radians(x.acos())
+    }
+
+    /// Calculates the arctangent of a number, in the configured angle unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number
+    ///
+    /// # Returns
+    ///
+    /// The arctangent of x.
+    #[must_use]
+    pub fn atan(&self, x: f64) -> f64 {
+        self.angle_from_radians(x.atan())
+    }
+
+    /// Calculates the four-quadrant arctangent of `y / x`, in the configured angle unit.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The y coordinate
+    /// * `x` - The x coordinate
+    ///
+    /// # Returns
+    ///
+    /// The angle between the positive x-axis and the point `(x, y)`.
+    pub fn atan2(&mut self, y: f64, x: f64) -> f64 {
+        let result = self.angle_from_radians(y.atan2(x));
+        self.basic.record(y, x, Operation::Atan2, result);
+        result
+    }
+
+    /// Calculates the hyperbolic sine of a number.
+    #[must_use]
+    pub fn sinh(&self, x: f64) -> f64 {
+        x.sinh()
+    }
+
+    /// Calculates the hyperbolic cosine of a number.
+    #[must_use]
+    pub fn cosh(&self, x: f64) -> f64 {
+        x.cosh()
+    }
+
+    /// Calculates the hyperbolic tangent of a number.
+    #[must_use]
+    pub fn tanh(&self, x: f64) -> f64 {
+        x.tanh()
+    }
+
+    /// Calculates `e` raised to the power of `x`.
+    #[must_use]
+    pub fn exp(&self, x: f64) -> f64 {
+        x.exp()
+    }
+
+    /// Calculates `2` raised to the power of `x`.
+    #[must_use]
+    pub fn exp2(&self, x: f64) -> f64 {
+        x.exp2()
     }
 
     /// Calculates the natural logarithm of a number.
@@ -338,6 +558,57 @@ impl ScientificCalculator {
         Ok(x.ln())
     }
 
+    /// Calculates the logarithm of a number in an arbitrary base.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number (must be positive)
+    /// * `base` - The logarithm base (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// The logarithm of `x` in the given `base`, or an error if `x <= 0`.
+    pub fn log(&mut self, x: f64, base: f64) -> Result<f64, CalculatorError> {
+        if x <= 0.0 {
+            return Err(CalculatorError::Overflow);
+        }
+        let result = x.log(base);
+        self.basic.record(x, base, Operation::Log, result);
+        Ok(result)
+    }
+
+    /// Calculates the base-10 logarithm of a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// The base-10 logarithm of x, or an error if x <= 0.
+    pub fn log10(&self, x: f64) -> Result<f64, CalculatorError> {
+        if x <= 0.0 {
+            return Err(CalculatorError::Overflow);
+        }
+        Ok(x.log10())
+    }
+
+    /// Calculates the base-2 logarithm of a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number (must be positive)
+    ///
+    /// # Returns
+    ///
+    /// The base-2 logarithm of x, or an error if x <= 0.
+    pub fn log2(&self, x: f64) -> Result<f64, CalculatorError> {
+        if x <= 0.0 {
+            return Err(CalculatorError::Overflow);
+        }
+        Ok(x.log2())
+    }
+
     /// Calculates the square root of a number.
     ///
     /// # Arguments
@@ -352,6 +623,59 @@ impl ScientificCalculator {
         x.sqrt()
     }
 
+    /// Calculates the cube root of a number.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number
+    ///
+    /// # Returns
+    ///
+    /// The cube root of x.
+    #[must_use]
+    pub fn cbrt(&self, x: f64) -> f64 {
+        x.cbrt()
+    }
+
+    /// Rounds a number down to the nearest integer.
+    #[must_use]
+    pub fn floor(&self, x: f64) -> f64 {
+        x.floor()
+    }
+
+    /// Rounds a number up to the nearest integer.
+    #[must_use]
+    pub fn ceil(&self, x: f64) -> f64 {
+        x.ceil()
+    }
+
+    /// Rounds a number to the nearest integer.
+    #[must_use]
+    pub fn round(&self, x: f64) -> f64 {
+        x.round()
+    }
+
+    /// Truncates a number's fractional part.
+    #[must_use]
+    pub fn trunc(&self, x: f64) -> f64 {
+        x.trunc()
+    }
+
+    /// Calculates the length of the hypotenuse of a right triangle, `sqrt(x^2 + y^2)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The length of one leg
+    /// * `y` - The length of the other leg
+    ///
+    /// # Returns
+    ///
+    /// The length of the hypotenuse.
+    #[must_use]
+    pub fn hypot(&self, x: f64, y: f64) -> f64 {
+        x.hypot(y)
+    }
+
     /// Raises a number to a power.
     ///
     /// # Arguments
@@ -362,9 +686,28 @@ impl ScientificCalculator {
     /// # Returns
     ///
     /// `base` raised to the power of `exponent`.
-    #[must_use]
-    pub fn pow(&self, base: f64, exponent: f64) -> f64 {
-        base.powf(exponent)
+    pub fn pow(&mut self, base: f64, exponent: f64) -> f64 {
+        let result = base.powf(exponent);
+        self.basic.record(base, exponent, Operation::Power, result);
+        result
+    }
+
+    /// Converts an angle to radians if `use_degrees` is set, otherwise leaves it as-is.
+    fn to_radians(&self, angle: f64) -> f64 {
+        if self.use_degrees {
+            angle.to_radians()
+        } else {
+            angle
+        }
+    }
+
+    /// Converts an angle from radians to degrees if `use_degrees` is set, otherwise leaves it as-is.
+    fn angle_from_radians(&self, angle: f64) -> f64 {
+        if self.use_degrees {
+            angle.to_degrees()
+        } else {
+            angle
+        }
     }
 
     /// Returns a reference to the underlying basic calculator.
@@ -420,4 +763,92 @@ mod tests {
         calc.subtract(5.0, 3.0);
         assert_eq!(calc.history().len(), 2);
     }
+
+    #[test]
+    fn test_integer_calculator() {
+        let mut calc = Calculator::<i64>::new();
+        assert_eq!(calc.add(2, 3), 5);
+        assert_eq!(calc.divide(10, 3).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_add_checked() {
+        let mut calc = Calculator::new();
+        assert_eq!(calc.add_checked(2.0, 3.0).unwrap(), 5.0);
+        assert!(calc.add_checked(f64::MAX, f64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_subtract_checked_overflow() {
+        let mut calc = Calculator::<i32>::new();
+        assert_eq!(calc.subtract_checked(5, 3).unwrap(), 2);
+        assert!(calc.subtract_checked(i32::MIN, 1).is_err());
+    }
+
+    #[test]
+    fn test_multiply_checked_overflow() {
+        let mut calc = Calculator::<i32>::new();
+        assert_eq!(calc.multiply_checked(3, 4).unwrap(), 12);
+        assert!(calc.multiply_checked(i32::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_scientific_tan() {
+        let sci = ScientificCalculator::new();
+        assert!((sci.tan(0.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_scientific_inverse_trig_degrees() {
+        let mut sci = ScientificCalculator::new();
+        sci.set_use_degrees(true);
+        assert!((sci.asin(1.0) - 90.0).abs() < 1e-9);
+        assert!((sci.atan2(1.0, 1.0) - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scientific_log() {
+        let mut sci = ScientificCalculator::new();
+        assert!((sci.log(8.0, 2.0).unwrap() - 3.0).abs() < 1e-10);
+        assert!((sci.log10(100.0).unwrap() - 2.0).abs() < 1e-10);
+        assert!(sci.log(-1.0, 2.0).is_err());
+    }
+
+    #[test]
+    fn test_scientific_rounding() {
+        let sci = ScientificCalculator::new();
+        assert_eq!(sci.floor(1.7), 1.0);
+        assert_eq!(sci.ceil(1.2), 2.0);
+        assert_eq!(sci.round(1.5), 2.0);
+        assert_eq!(sci.trunc(1.9), 1.0);
+    }
+
+    #[test]
+    fn test_scientific_hypot() {
+        let sci = ScientificCalculator::new();
+        assert_eq!(sci.hypot(3.0, 4.0), 5.0);
+    }
+
+    #[test]
+    fn test_scientific_records_binary_ops() {
+        let mut sci = ScientificCalculator::new();
+        sci.pow(2.0, 10.0);
+        sci.atan2(1.0, 1.0);
+        sci.log(8.0, 2.0).unwrap();
+        assert_eq!(sci.basic().history().len(), 3);
+    }
+
+    #[test]
+    fn test_operand_ops() {
+        let sum = Operand(5.0) + Operand(3.0);
+        assert_eq!(sum.result, 8.0);
+        assert_eq!(sum.to_string(), "5 + 3 = 8");
+    }
+
+    #[test]
+    fn test_operand_chaining() {
+        let chained = (Operand(5.0) + Operand(3.0)) * Operand(2.0);
+        assert_eq!(chained.result, 16.0);
+        assert_eq!(chained.operand_a, 8.0);
+    }
 }
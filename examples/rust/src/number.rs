@@ -0,0 +1,98 @@
+//! A minimal numeric trait used to make the calculator generic.
+
+use std::fmt::{Debug, Display};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// A type that supports the basic arithmetic the calculator needs.
+///
+/// This mirrors the handful of operations `num-traits`' `Num` trait
+/// provides, without pulling in the dependency: the four arithmetic
+/// operators plus a `zero`/`is_zero` pair so [`Calculator::divide`]
+/// can detect division by zero for both floating-point and integer
+/// types, and a set of `checked_*` operations (mirroring `num-traits`'
+/// `CheckedAdd`/`CheckedSub`/`CheckedMul`) so [`Calculator::add_checked`]
+/// and friends can detect overflow for both kinds of types too.
+///
+/// [`Calculator::divide`]: crate::Calculator::divide
+/// [`Calculator::add_checked`]: crate::Calculator::add_checked
+pub trait Number:
+    Copy
+    + PartialEq
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The additive identity for this type.
+    fn zero() -> Self;
+
+    /// Returns `true` if this value is equal to [`Number::zero`].
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// Adds two values, returning `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtracts `other` from `self`, returning `None` on overflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Multiplies two values, returning `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_number_float {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Number for $ty {
+                fn zero() -> Self {
+                    0 as $ty
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    let result = self + other;
+                    result.is_finite().then_some(result)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    let result = self - other;
+                    result.is_finite().then_some(result)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    let result = self * other;
+                    result.is_finite().then_some(result)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_number_int {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Number for $ty {
+                fn zero() -> Self {
+                    0 as $ty
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_float!(f32, f64);
+impl_number_int!(i32, i64, i128, isize);
@@ -1,5 +1,7 @@
 //! Operation types and results for the calculator.
 
+use crate::Number;
+
 /// Enumeration of supported arithmetic operations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Operation {
@@ -11,6 +13,18 @@ pub enum Operation {
     Multiply,
     /// Division operation (/)
     Divide,
+    /// Exponentiation, as performed by [`ScientificCalculator::pow`] (^)
+    ///
+    /// [`ScientificCalculator::pow`]: crate::ScientificCalculator::pow
+    Power,
+    /// The two-argument arctangent, as performed by [`ScientificCalculator::atan2`]
+    ///
+    /// [`ScientificCalculator::atan2`]: crate::ScientificCalculator::atan2
+    Atan2,
+    /// A logarithm to an arbitrary base, as performed by [`ScientificCalculator::log`]
+    ///
+    /// [`ScientificCalculator::log`]: crate::ScientificCalculator::log
+    Log,
 }
 
 impl std::fmt::Display for Operation {
@@ -20,24 +34,27 @@ impl std::fmt::Display for Operation {
             Self::Subtract => write!(f, "-"),
             Self::Multiply => write!(f, "*"),
             Self::Divide => write!(f, "/"),
+            Self::Power => write!(f, "^"),
+            Self::Atan2 => write!(f, "atan2"),
+            Self::Log => write!(f, "log"),
         }
     }
 }
 
 /// The result of a calculation, including operands and the operation performed.
 #[derive(Debug, Clone)]
-pub struct OperationResult {
+pub struct OperationResult<T: Number = f64> {
     /// The first operand
-    pub operand_a: f64,
+    pub operand_a: T,
     /// The second operand
-    pub operand_b: f64,
+    pub operand_b: T,
     /// The operation that was performed
     pub operation: Operation,
     /// The calculated result
-    pub result: f64,
+    pub result: T,
 }
 
-impl std::fmt::Display for OperationResult {
+impl<T: Number> std::fmt::Display for OperationResult<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -46,3 +63,62 @@ impl std::fmt::Display for OperationResult {
         )
     }
 }
+
+/// A lightweight `f64` newtype that supports `std::ops` operators, producing
+/// an [`OperationResult`] rather than a bare number.
+///
+/// This gives an ergonomic, expression-building alternative to the stateful
+/// [`Calculator`](crate::Calculator): `Operand(5.0) + Operand(3.0)` yields an
+/// `OperationResult` that already `Display`s as `5 + 3 = 8`, and results
+/// chain naturally because the arithmetic traits are also implemented
+/// between [`OperationResult`] and `Operand`.
+///
+/// # Examples
+///
+/// ```rust
+/// use calculator::Operand;
+///
+/// let sum = Operand(5.0) + Operand(3.0);
+/// assert_eq!(sum.result, 8.0);
+/// assert_eq!(sum.to_string(), "5 + 3 = 8");
+///
+/// let chained = (Operand(5.0) + Operand(3.0)) * Operand(2.0);
+/// assert_eq!(chained.result, 16.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Operand(pub f64);
+
+macro_rules! impl_operand_op {
+    ($trait:ident, $method:ident, $op:tt, $variant:ident) => {
+        impl std::ops::$trait for Operand {
+            type Output = OperationResult;
+
+            fn $method(self, rhs: Self) -> OperationResult {
+                OperationResult {
+                    operand_a: self.0,
+                    operand_b: rhs.0,
+                    operation: Operation::$variant,
+                    result: self.0 $op rhs.0,
+                }
+            }
+        }
+
+        impl std::ops::$trait<Operand> for OperationResult {
+            type Output = OperationResult;
+
+            fn $method(self, rhs: Operand) -> OperationResult {
+                OperationResult {
+                    operand_a: self.result,
+                    operand_b: rhs.0,
+                    operation: Operation::$variant,
+                    result: self.result $op rhs.0,
+                }
+            }
+        }
+    };
+}
+
+impl_operand_op!(Add, add, +, Add);
+impl_operand_op!(Sub, sub, -, Subtract);
+impl_operand_op!(Mul, mul, *, Multiply);
+impl_operand_op!(Div, div, /, Divide);